@@ -0,0 +1,46 @@
+/*
+A tiny ANSI color helper for the board and guessed-letter feedback. Colors are
+applied through a Palette that can be turned off - either by the `--no-color`
+flag or when standard output is not a terminal - so piped output stays clean.
+*/
+
+use std::io::IsTerminal;
+
+// Carries whether color output is enabled for this run.
+pub struct Palette {
+    enabled: bool,
+}
+
+impl Palette {
+    // Enable color only for an interactive terminal and when not disabled.
+    pub fn detect(no_color: bool) -> Self {
+        Palette {
+            enabled: !no_color && std::io::stdout().is_terminal(),
+        }
+    }
+
+    // Wrap text in an ANSI escape, or return it untouched when color is off.
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.enabled {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn green(&self, text: &str) -> String {
+        self.paint("32", text)
+    }
+
+    pub fn red(&self, text: &str) -> String {
+        self.paint("31", text)
+    }
+
+    pub fn yellow(&self, text: &str) -> String {
+        self.paint("33", text)
+    }
+
+    pub fn dim(&self, text: &str) -> String {
+        self.paint("2", text)
+    }
+}
@@ -10,65 +10,147 @@ HashMaps, slicing, and error handling.
 use std::io;
 use std::collections::HashMap;
 
+mod color;
+mod solver;
+
+use color::Palette;
+
+// A loaded bank groups words (and their descriptions) under named categories.
+type WordBank = HashMap<String, HashMap<String, String>>;
+
+// The state of the session after a guess is processed. The plain Victory/Defeat
+// variants mean the current round is over but more words are still queued, while
+// the *GameOver variants mean the whole session has ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GameState {
+    Ongoing,
+    Victory,
+    Defeat,
+    VictoryGameOver,
+    DefeatGameOver,
+}
+
+// A single turn's input: either one letter or an attempt at the whole word
+enum Guess {
+    Letter(char),
+    Word(String),
+}
+
 // Struct for game state
 struct HangmanGame {
     secret_word: String,
     display_word: Vec<char>,
     guessed_letters: Vec<char>,
+    guessed_words: Vec<String>,
     wrong_guesses: i32,
     max_wrong: i32,
-    word_bank: HashMap<&'static str, &'static str>, // Hashmap
+    word_bank: HashMap<String, String>, // Hashmap of word -> description
+    remaining_words: Vec<String>, // Words still to play this session
+    state: GameState,
+    rounds_won: i32,
+    rounds_lost: i32,
 }
 
 impl HangmanGame {
-    // Constructor
-    fn new() -> Self {
-        let word_bank = HashMap::from([
+    // The built-in word bank used as a fallback when no JSON file is available
+    fn default_word_bank() -> HashMap<String, String> {
+        [
             ("RUST", "A systems programming language"),
             ("JAVA", "Write once, run anywhere"),
             ("SWIFT", "Apple's programming language"),
             ("PYTHON", "Known for its simplicity"),
             ("GOLANG", "Created by Google")
-        ]);
-        
-        // Get a random word from the word bank
-        let words: Vec<&str> = word_bank.keys().copied().collect();
-        let secret_word = words[2].to_string(); // Using SWIFT
-        
+        ]
+        .iter()
+        .map(|(word, desc)| (word.to_string(), desc.to_string()))
+        .collect()
+    }
+
+    // Shared builder used by both the bank-driven and human-supplied flows
+    fn build(
+        secret_word: String,
+        remaining_words: Vec<String>,
+        word_bank: HashMap<String, String>,
+    ) -> Self {
         HangmanGame {
             display_word: vec!['_'; secret_word.len()],
             guessed_letters: Vec::new(),
+            guessed_words: Vec::new(),
             wrong_guesses: 0,
             max_wrong: 6,
             word_bank,
+            remaining_words,
+            state: GameState::Ongoing,
+            rounds_won: 0,
+            rounds_lost: 0,
             secret_word,
         }
     }
+
+    // Constructor that draws the secret word from the given word bank
+    fn new_random(word_bank: HashMap<String, String>) -> Self {
+        // Get a random word from the word bank, queueing the rest for later rounds.
+        // The game pulls in no RNG crate, so we seed the pick from the system clock.
+        let mut words: Vec<String> = word_bank.keys().cloned().collect();
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let index = (seed as usize) % words.len();
+        let secret_word = words.remove(index);
+        let remaining_words = words;
+
+        Self::build(secret_word, remaining_words, word_bank)
+    }
+
+    // Constructor that uses a word supplied by another player. Such words are not
+    // in the bank, so the hint/description lines simply won't appear.
+    fn new_with_word(secret: &str) -> Self {
+        // An empty bank keeps the supplied word out of the hint/description paths.
+        Self::build(secret.to_uppercase(), Vec::new(), HashMap::new())
+    }
     
     // Method to display current game state
-    fn display_game(&self) {
+    fn display_game(&self, palette: &Palette) {
         println!("\n{}", "=".repeat(40));
         println!("HANGMAN GAME - GUESS THE PROGRAMMING LANGUAGE");
         println!("{}", "=".repeat(40));
-        
-        // Display the word with spaces between letters
+
+        // Display the word with spaces between letters: revealed letters in
+        // green, the blanks dimmed so the eye goes straight to progress.
         print!("Word: ");
         for letter in &self.display_word {
-            print!("{} ", letter);
+            if *letter == '_' {
+                print!("{} ", palette.dim("_"));
+            } else {
+                print!("{} ", palette.green(&letter.to_string()));
+            }
         }
         println!();
-        
-        // Display guessed letters
+
+        // Display guessed letters, split into correct (green) and wrong (red)
         if !self.guessed_letters.is_empty() {
             print!("Guessed letters: ");
             for letter in &self.guessed_letters {
-                print!("{} ", letter);
+                let shown = letter.to_string();
+                if self.secret_word.contains(*letter) {
+                    print!("{} ", palette.green(&shown));
+                } else {
+                    print!("{} ", palette.red(&shown));
+                }
             }
             println!();
         }
-        
-        // Display hangman status
-        println!("Wrong guesses: {}/{}", self.wrong_guesses, self.max_wrong);
+
+        // Display hangman status, warning in yellow then red near the limit
+        let status = format!("Wrong guesses: {}/{}", self.wrong_guesses, self.max_wrong);
+        if self.wrong_guesses >= self.max_wrong - 1 {
+            println!("{}", palette.red(&status));
+        } else if self.wrong_guesses >= self.max_wrong - 3 {
+            println!("{}", palette.yellow(&status));
+        } else {
+            println!("{}", status);
+        }
         self.display_hangman();
         
         // Give hint after 3 wrong guesses
@@ -145,12 +227,12 @@ impl HangmanGame {
         println!("{}", stages[stage_index]);
     }
     
-    // Method to process a guess
-    fn process_guess(&mut self, guess: char) -> bool {
+    // Method to process a single-letter guess, returning the resulting session state
+    fn process_letter(&mut self, guess: char) -> GameState {
         self.guessed_letters.push(guess);
-        
+
         let mut correct_guess = false;
-        
+
         // Check if guess is in secret word
         for (i, letter) in self.secret_word.chars().enumerate() {
             if letter == guess {
@@ -158,24 +240,70 @@ impl HangmanGame {
                 correct_guess = true;
             }
         }
-        
+
         if !correct_guess {
             self.wrong_guesses += 1;
         }
-        
-        correct_guess
+
+        self.state = self.evaluate_state();
+        self.state
     }
-    
-    // Method to check if game is won
-    fn is_won(&self) -> bool {
-        !self.display_word.contains(&'_')
+
+    // Method to process a full-word guess. Returns true when it matches the
+    // secret word; a miss costs a wrong guess just like a missed letter.
+    fn process_word(&mut self, guess: &str) -> bool {
+        let guess = guess.trim().to_uppercase();
+        self.guessed_words.push(guess.clone());
+
+        if guess == self.secret_word {
+            self.display_word = self.secret_word.chars().collect();
+            self.state = self.evaluate_state();
+            true
+        } else {
+            self.wrong_guesses += 1;
+            self.state = self.evaluate_state();
+            false
+        }
     }
-    
-    // Method to check if game is lost
-    fn is_lost(&self) -> bool {
-        self.wrong_guesses >= self.max_wrong
+
+    // Method to classify the current round into a session state
+    fn evaluate_state(&self) -> GameState {
+        if !self.display_word.contains(&'_') {
+            if self.remaining_words.is_empty() {
+                GameState::VictoryGameOver
+            } else {
+                GameState::Victory
+            }
+        } else if self.wrong_guesses >= self.max_wrong {
+            if self.remaining_words.is_empty() {
+                GameState::DefeatGameOver
+            } else {
+                GameState::Defeat
+            }
+        } else {
+            GameState::Ongoing
+        }
     }
-    
+
+    // Method to start the next queued word after a round ends
+    fn deal_next_word(&mut self) {
+        match self.state {
+            GameState::Victory | GameState::VictoryGameOver => self.rounds_won += 1,
+            GameState::Defeat | GameState::DefeatGameOver => self.rounds_lost += 1,
+            GameState::Ongoing => {}
+        }
+
+        if !self.remaining_words.is_empty() {
+            let word = self.remaining_words.remove(0);
+            self.secret_word = word.to_string();
+            self.display_word = vec!['_'; word.len()];
+            self.guessed_letters.clear();
+            self.guessed_words.clear();
+            self.wrong_guesses = 0;
+            self.state = GameState::Ongoing;
+        }
+    }
+
     // Method to reveal the word using slicing
     fn reveal_partial_word(&self) -> String {
         if self.secret_word.len() > 2 {
@@ -189,26 +317,315 @@ impl HangmanGame {
     }
 }
 
-// Function to get valid letter input from user
-fn get_player_input() -> char {
+// Function to get a valid guess from the user: a single letter or a full word
+fn get_player_input() -> Guess {
     loop {
-        println!("\nEnter a single letter (A-Z):");
-        
+        println!("\nEnter a letter, or type the whole word (A-Z):");
+
         let mut input = String::new();
-        
+
         match io::stdin().read_line(&mut input) {
             Ok(_) => {
                 let cleaned_input = input.trim().to_uppercase();
-                
-                // Validate input
-                if cleaned_input.len() == 1 {
-                    let character = cleaned_input.chars().next().unwrap();
-                    if character.is_alphabetic() {
-                        return character;
+
+                // Validate input: letters only, one or more of them
+                if !cleaned_input.is_empty()
+                    && cleaned_input.chars().all(|c| c.is_ascii_alphabetic())
+                {
+                    if cleaned_input.len() == 1 {
+                        return Guess::Letter(cleaned_input.chars().next().unwrap());
                     }
+                    return Guess::Word(cleaned_input);
                 }
-                
-                println!("Please enter exactly one letter!");
+
+                println!("Please enter letters only!");
+            }
+            Err(error) => {
+                println!("Error reading input: {}", error);
+            }
+        }
+    }
+}
+
+// Function to load a categorized word bank from a JSON file. Accepts either a
+// flat `{ "word": "description" }` object or one grouped under named categories,
+// uppercasing every word. Returns None if the file is missing or malformed so
+// the caller can fall back to the built-in bank.
+fn load_word_bank(path: &str) -> Option<WordBank> {
+    let data = std::fs::read_to_string(path).ok()?;
+
+    // The top level is always a single JSON object.
+    let JsonValue::Object(entries) = parse_json(&data)? else {
+        return None;
+    };
+
+    // A categorized file: { "languages": { "RUST": "..." }, ... }
+    if !entries.is_empty() && entries.iter().all(|(_, v)| matches!(v, JsonValue::Object(_))) {
+        let mut categories = HashMap::new();
+        for (name, value) in entries {
+            if let JsonValue::Object(words) = value {
+                categories.insert(name, clean_words(object_to_map(words)?));
+            }
+        }
+        return non_empty_bank(categories);
+    }
+
+    // A flat file: { "RUST": "..." } is treated as a single "words" category.
+    if entries.iter().all(|(_, v)| matches!(v, JsonValue::String(_))) {
+        let mut categories = HashMap::new();
+        categories.insert("words".to_string(), clean_words(object_to_map(entries)?));
+        return non_empty_bank(categories);
+    }
+
+    None
+}
+
+// The subset of JSON the word bank needs: string values and nested objects.
+enum JsonValue {
+    String(String),
+    Object(Vec<(String, JsonValue)>),
+}
+
+// Collapse a parsed object into a word -> description map, rejecting it if any
+// value is itself an object rather than a plain string.
+fn object_to_map(entries: Vec<(String, JsonValue)>) -> Option<HashMap<String, String>> {
+    let mut map = HashMap::new();
+    for (key, value) in entries {
+        match value {
+            JsonValue::String(text) => {
+                map.insert(key, text);
+            }
+            JsonValue::Object(_) => return None,
+        }
+    }
+    Some(map)
+}
+
+// Parse a whole JSON document, succeeding only if it is a single value with no
+// trailing junk. Returns None on any malformed input so the caller falls back to
+// the built-in bank. Kept deliberately small - it handles just the string and
+// object shapes the word bank uses, not the full JSON grammar.
+fn parse_json(data: &str) -> Option<JsonValue> {
+    // Tolerate a leading UTF-8 BOM that some editors prepend.
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+
+    let mut chars = data.chars().peekable();
+    let value = parse_value(&mut chars, 0)?;
+    skip_whitespace(&mut chars);
+    if chars.peek().is_some() {
+        return None;
+    }
+    Some(value)
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+// Cap on object nesting so malformed, deeply nested input returns None (and the
+// caller falls back to the built-in bank) rather than overflowing the stack.
+const MAX_DEPTH: u8 = 32;
+
+// Parse a single value: either a quoted string or a `{ ... }` object.
+fn parse_value(chars: &mut Chars, depth: u8) -> Option<JsonValue> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+
+    skip_whitespace(chars);
+    match chars.peek()? {
+        '"' => parse_string(chars).map(JsonValue::String),
+        '{' => parse_object(chars, depth).map(JsonValue::Object),
+        _ => None,
+    }
+}
+
+// Parse a `{ "key": value, ... }` object into key/value pairs in file order.
+fn parse_object(chars: &mut Chars, depth: u8) -> Option<Vec<(String, JsonValue)>> {
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    let mut entries = Vec::new();
+    skip_whitespace(chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+        return Some(entries);
+    }
+
+    loop {
+        skip_whitespace(chars);
+        let key = parse_string(chars)?;
+        skip_whitespace(chars);
+        if chars.next()? != ':' {
+            return None;
+        }
+        let value = parse_value(chars, depth + 1)?;
+        entries.push((key, value));
+
+        skip_whitespace(chars);
+        match chars.next()? {
+            ',' => continue,
+            '}' => return Some(entries),
+            _ => return None,
+        }
+    }
+}
+
+// Parse a double-quoted string, honouring the common JSON escape sequences.
+fn parse_string(chars: &mut Chars) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(text),
+            '\\' => match chars.next()? {
+                '"' => text.push('"'),
+                '\\' => text.push('\\'),
+                '/' => text.push('/'),
+                'n' => text.push('\n'),
+                't' => text.push('\t'),
+                'r' => text.push('\r'),
+                'b' => text.push('\u{0008}'),
+                'f' => text.push('\u{000C}'),
+                'u' => text.push(parse_unicode_escape(chars)?),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+    }
+}
+
+// Decode the four hex digits of a `\uXXXX` escape into a character. Each
+// escape is decoded on its own, so any surrogate code unit is rejected as
+// malformed, including both halves of a surrogate pair.
+fn parse_unicode_escape(chars: &mut Chars) -> Option<char> {
+    let mut code: u32 = 0;
+    for _ in 0..4 {
+        code = code * 16 + chars.next()?.to_digit(16)?;
+    }
+    char::from_u32(code)
+}
+
+// Skip any run of JSON whitespace before the next meaningful character.
+fn skip_whitespace(chars: &mut Chars) {
+    while matches!(chars.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+        chars.next();
+    }
+}
+
+// Helper to uppercase and keep only plain alphabetic words, matching the rest
+// of the game's ASCII-only assumption.
+fn clean_words(words: HashMap<String, String>) -> HashMap<String, String> {
+    words
+        .into_iter()
+        .filter(|(word, _)| !word.is_empty() && word.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|(word, desc)| (word.to_uppercase(), desc))
+        .collect()
+}
+
+// Helper to drop empty categories and reject a bank with no usable words, so the
+// caller falls back to the built-in defaults instead of hitting an empty round.
+fn non_empty_bank(bank: WordBank) -> Option<WordBank> {
+    let bank: WordBank = bank.into_iter().filter(|(_, words)| !words.is_empty()).collect();
+    if bank.is_empty() {
+        None
+    } else {
+        Some(bank)
+    }
+}
+
+// Function to build the fallback bank from the built-in defaults
+fn default_word_bank() -> WordBank {
+    let mut categories = HashMap::new();
+    categories.insert("languages".to_string(), HangmanGame::default_word_bank());
+    categories
+}
+
+// Function to let the player pick which category to draw the secret word from
+fn choose_category(bank: &WordBank) -> HashMap<String, String> {
+    let mut names: Vec<&String> = bank.keys().collect();
+    names.sort();
+
+    // Nothing to choose between a single category
+    if names.len() <= 1 {
+        return names
+            .first()
+            .map(|name| bank[*name].clone())
+            .unwrap_or_default();
+    }
+
+    loop {
+        println!("\nChoose a category:");
+        for (i, name) in names.iter().enumerate() {
+            println!("  {}) {}", i + 1, name);
+        }
+
+        let mut input = String::new();
+
+        match io::stdin().read_line(&mut input) {
+            Ok(_) => {
+                if let Ok(choice) = input.trim().parse::<usize>() {
+                    if (1..=names.len()).contains(&choice) {
+                        return bank[names[choice - 1]].clone();
+                    }
+                }
+                println!("Please enter a number between 1 and {}.", names.len());
+            }
+            Err(error) => {
+                println!("Error reading input: {}", error);
+            }
+        }
+    }
+}
+
+// Function to clear the terminal so the next player can't see the chosen word
+fn clear_terminal() {
+    use std::io::Write;
+    print!("\x1B[2J\x1B[1;1H");
+    let _ = io::stdout().flush();
+}
+
+// Function to choose the game mode at startup
+fn choose_mode() -> u8 {
+    loop {
+        println!("\nChoose a mode:");
+        println!("  1) Single player (computer picks the word)");
+        println!("  2) Two player (a friend types the word)");
+
+        let mut input = String::new();
+
+        match io::stdin().read_line(&mut input) {
+            Ok(_) => match input.trim() {
+                "1" => return 1,
+                "2" => return 2,
+                _ => println!("Please enter 1 or 2."),
+            },
+            Err(error) => println!("Error reading input: {}", error),
+        }
+    }
+}
+
+// Function to read a secret word typed by the first player
+fn get_secret_word() -> String {
+    loop {
+        println!("\nPlayer 1, enter the secret word (letters only):");
+
+        let mut input = String::new();
+
+        match io::stdin().read_line(&mut input) {
+            Ok(_) => {
+                let cleaned_input = input.trim().to_uppercase();
+
+                // Validate input: alphabetic and a reasonable length
+                if (3..=20).contains(&cleaned_input.len())
+                    && cleaned_input.chars().all(|c| c.is_ascii_alphabetic())
+                {
+                    return cleaned_input;
+                }
+
+                println!("Please enter an alphabetic word of 3-20 letters!");
             }
             Err(error) => {
                 println!("Error reading input: {}", error);
@@ -244,51 +661,109 @@ fn show_result(game: &HangmanGame, won: bool) {
     println!("{}", "=".repeat(40));
 }
 
+// Function to display the final session scoreboard
+fn show_session_stats(game: &HangmanGame) {
+    println!("\n{}", "=".repeat(40));
+    println!("SESSION COMPLETE");
+    println!("Rounds won:  {}", game.rounds_won);
+    println!("Rounds lost: {}", game.rounds_lost);
+    println!("{}", "=".repeat(40));
+}
+
 // Main game function
 fn main() {
+    // Run the solver benchmark instead of an interactive game when asked.
+    if std::env::args().any(|arg| arg == "--benchmark") {
+        solver::benchmark();
+        return;
+    }
+
+    // Detect whether to colorize: honored unless `--no-color` or a non-TTY.
+    let palette = Palette::detect(std::env::args().any(|arg| arg == "--no-color"));
+
     println!("Welcome to Programming Language Hangman!");
     show_instructions();
-    
-    // Create new game instance
-    let mut game = HangmanGame::new();
-    
+
+    // Load the word bank from disk, falling back to the built-in defaults
+    let bank = load_word_bank("words.json").unwrap_or_else(default_word_bank);
+
+    // Create new game instance based on the chosen mode
+    let mut game = match choose_mode() {
+        2 => {
+            let secret = get_secret_word();
+            clear_terminal(); // Hide the word from the guessing player
+            HangmanGame::new_with_word(&secret)
+        }
+        _ => HangmanGame::new_random(choose_category(&bank)),
+    };
+
     // Main game loop
     loop {
         // Display current game state
-        game.display_game();
-        
-        // Check game status
-        if game.is_won() {
-            show_result(&game, true);
-            break;
-        }
-        
-        if game.is_lost() {
-            show_result(&game, false);
-            break;
+        game.display_game(&palette);
+
+        match game.state {
+            GameState::Victory => {
+                show_result(&game, true);
+                game.deal_next_word();
+                continue;
+            }
+            GameState::Defeat => {
+                show_result(&game, false);
+                game.deal_next_word();
+                continue;
+            }
+            GameState::VictoryGameOver => {
+                show_result(&game, true);
+                game.deal_next_word();
+                show_session_stats(&game);
+                break;
+            }
+            GameState::DefeatGameOver => {
+                show_result(&game, false);
+                game.deal_next_word();
+                show_session_stats(&game);
+                break;
+            }
+            GameState::Ongoing => {}
         }
-        
+
         // Show additional hint using slicing
         if game.wrong_guesses == 2 {
             println!("{}", game.reveal_partial_word());
         }
-        
-        // Get player input
-        let guess = get_player_input();
-        
-        // Check if letter was already guessed
-        if game.guessed_letters.contains(&guess) {
-            println!("You already guessed '{}'! Try a different letter.", guess);
-            continue;
-        }
-        
-        // Process the guess
-        let correct = game.process_guess(guess);
-        
-        if correct {
-            println!("Good guess! '{}' is in the word.", guess);
-        } else {
-            println!("Sorry, '{}' is not in the word.", guess);
+
+        // Get player input and dispatch on letter vs. full-word guess
+        match get_player_input() {
+            Guess::Letter(guess) => {
+                // Check if letter was already guessed
+                if game.guessed_letters.contains(&guess) {
+                    println!("You already guessed '{}'! Try a different letter.", guess);
+                    continue;
+                }
+
+                let correct = game.secret_word.contains(guess);
+                game.process_letter(guess);
+
+                if correct {
+                    println!("Good guess! '{}' is in the word.", guess);
+                } else {
+                    println!("Sorry, '{}' is not in the word.", guess);
+                }
+            }
+            Guess::Word(guess) => {
+                // Check if this word was already attempted
+                if game.guessed_words.contains(&guess) {
+                    println!("You already tried '{}'! Try a different word.", guess);
+                    continue;
+                }
+
+                if game.process_word(&guess) {
+                    println!("Correct! The word was '{}'.", guess);
+                } else {
+                    println!("Sorry, '{}' is not the word.", guess);
+                }
+            }
         }
     }
 }
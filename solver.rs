@@ -0,0 +1,139 @@
+/*
+An automated Hangman solver used to gauge how hard the word bank is. The
+solver only ever sees what a human player sees - the revealed pattern and
+the letters already guessed - and picks the next letter with the classic frequency-filtering strategy.
+*/
+
+use std::collections::HashSet;
+
+use crate::{GameState, HangmanGame};
+
+// Letters ordered by overall English frequency, used to break ties.
+const ENGLISH_FREQUENCY: &str = "ETAOINSHRDLCUMWFGYPBVKJXQZ";
+
+// A solver that narrows a candidate list down as letters are revealed.
+pub struct Solver {
+    candidates: Vec<String>,
+}
+
+impl Solver {
+    // Start from every bank word whose length matches the secret word.
+    pub fn new(bank: &[String], word_len: usize) -> Self {
+        let candidates = bank
+            .iter()
+            .map(|w| w.to_uppercase())
+            .filter(|w| w.chars().count() == word_len)
+            .collect();
+
+        Solver { candidates }
+    }
+
+    // Pick the next letter to guess from the current board.
+    pub fn next_letter(&mut self, display_word: &[char], guessed_letters: &[char]) -> char {
+        let guessed: HashSet<char> = guessed_letters.iter().copied().collect();
+        let revealed: HashSet<char> = display_word.iter().copied().filter(|c| *c != '_').collect();
+
+        // Keep only candidates consistent with everything revealed so far: known
+        // letters sit at exactly the revealed positions, blanks hold none of the
+        // revealed letters, and absent guesses appear nowhere.
+        self.candidates.retain(|word| {
+            let chars: Vec<char> = word.chars().collect();
+
+            for (i, shown) in display_word.iter().enumerate() {
+                if *shown != '_' {
+                    if chars[i] != *shown {
+                        return false;
+                    }
+                } else if revealed.contains(&chars[i]) {
+                    return false;
+                }
+            }
+
+            guessed
+                .iter()
+                .all(|g| revealed.contains(g) || !chars.contains(g))
+        });
+
+        // Tally, across surviving candidates, how many contain each unguessed
+        // letter and guess the most common one.
+        let mut tally: [u32; 26] = [0; 26];
+        for word in &self.candidates {
+            let letters: HashSet<char> = word.chars().collect();
+            for letter in letters {
+                if letter.is_ascii_uppercase() && !guessed.contains(&letter) {
+                    tally[(letter as u8 - b'A') as usize] += 1;
+                }
+            }
+        }
+
+        let mut best: Option<char> = None;
+        let mut best_count = 0;
+        for letter in ENGLISH_FREQUENCY.chars() {
+            if guessed.contains(&letter) {
+                continue;
+            }
+            let count = tally[(letter as u8 - b'A') as usize];
+            // ENGLISH_FREQUENCY is already in priority order, so the first letter
+            // with a strictly greater count wins, which breaks ties for us.
+            if best.is_none() || count > best_count {
+                best = Some(letter);
+                best_count = count;
+            }
+        }
+
+        // If the candidate list was emptied (e.g. a secret word outside the bank)
+        // the tally is all zeros, but the loop above still seeds `best` with the
+        // first unguessed frequency letter. Only once every letter has been
+        // guessed does `best` stay `None`; fall back to the most common English
+        // letter rather than panicking, since the solver is a public API.
+        best.unwrap_or_else(|| ENGLISH_FREQUENCY.chars().next().unwrap())
+    }
+}
+
+// Summary of a single solved (or failed) word.
+struct BenchmarkResult {
+    word: String,
+    solved: bool,
+    wrong_guesses: i32,
+}
+
+// Play the solver against every word in the built-in bank and report how it did.
+pub fn benchmark() {
+    let bank = HangmanGame::default_word_bank();
+    let words: Vec<String> = bank.keys().map(|w| w.to_string()).collect();
+
+    println!("\n{}", "=".repeat(40));
+    println!("SOLVER BENCHMARK");
+    println!("{}", "=".repeat(40));
+
+    let mut results = Vec::new();
+    for word in &words {
+        let mut game = HangmanGame::new_with_word(word);
+        let mut solver = Solver::new(&words, word.chars().count());
+
+        while game.state == GameState::Ongoing {
+            let letter = solver.next_letter(&game.display_word, &game.guessed_letters);
+            game.process_letter(letter);
+        }
+
+        results.push(BenchmarkResult {
+            word: word.clone(),
+            solved: !game.display_word.contains(&'_'),
+            wrong_guesses: game.wrong_guesses,
+        });
+    }
+
+    let solved = results.iter().filter(|r| r.solved).count();
+    for result in &results {
+        println!(
+            "{:<10} {:<7} wrong guesses: {}",
+            result.word,
+            if result.solved { "SOLVED" } else { "FAILED" },
+            result.wrong_guesses
+        );
+    }
+
+    println!("{}", "-".repeat(40));
+    println!("Solve rate: {}/{}", solved, results.len());
+    println!("{}", "=".repeat(40));
+}